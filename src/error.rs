@@ -10,9 +10,7 @@ use std::num;
 /// As this crate exposes an opinionated API to the POSIX queues certain errors have been
 /// ignored:
 ///
-/// * ETIMEDOUT: The low-level timed functions are not exported and this error can not occur.
-/// * EAGAIN: Non-blocking queue calls are not supported.
-/// * EINVAL: Same reason as ETIMEDOUT
+/// * EINVAL: Arguments are built internally and should always be valid.
 /// * EMSGSIZE: The message size is immutable after queue creation and this crate checks it.
 /// * ENAMETOOLONG: This crate performs name validation
 ///
@@ -20,6 +18,7 @@ use std::num;
 /// as a bug on https://github.com/aprilabank/posix_mq.rs
 
 #[derive(Debug)]
+#[allow(clippy::enum_variant_names)]
 pub enum Error {
     // These errors are raised inside of the library
     InvalidQueueName(&'static str),
@@ -36,6 +35,9 @@ pub enum Error {
     QueueNotFound(),
     InsufficientMemory(),
     InsufficientSpace(),
+    QueueCallTimedOut(),
+    WouldBlock(),
+    NotifyAlreadyRegistered(),
 
     // These two are (hopefully) unlikely in modern systems
     ProcessFileDescriptorLimitReached(),
@@ -50,7 +52,7 @@ pub enum Error {
     UnknownInternalError(Option<nix::Error>),
 }
 
-impl error::Error for Error {
+impl Error {
     fn description(&self) -> &str {
         use Error::*;
         match *self {
@@ -67,6 +69,10 @@ impl error::Error for Error {
             QueueNotFound() => "the specified queue could not be found",
             InsufficientMemory() => "insufficient memory to call queue method",
             InsufficientSpace() => "insufficient space to call queue method",
+            QueueCallTimedOut() => "timed queue method expired before it could complete",
+            WouldBlock() => "non-blocking queue method would have to block to complete",
+            NotifyAlreadyRegistered() =>
+                "another process is already registered for notifications on this queue",
             ProcessFileDescriptorLimitReached() =>
                 "maximum number of process file descriptors reached",
             SystemFileDescriptorLimitReached() =>
@@ -77,10 +83,10 @@ impl error::Error for Error {
     }
 }
 
+impl error::Error for Error {}
+
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        // Explicitly import this to gain access to Error::description()
-        use std::error::Error;
         f.write_str(self.description())
     }
 }
@@ -125,6 +131,9 @@ fn match_errno(err: nix::Errno) -> Error {
         ENOENT => Error::QueueNotFound(),
         ENOMEM => Error::InsufficientMemory(),
         ENOSPC => Error::InsufficientSpace(),
+        ETIMEDOUT => Error::QueueCallTimedOut(),
+        EAGAIN => Error::WouldBlock(),
+        EBUSY  => Error::NotifyAlreadyRegistered(),
         _      => Error::UnknownForeignError(err),
     }
 }
@@ -6,13 +6,21 @@ use libc::mqd_t;
 use nix::mqueue;
 use nix::sys::stat;
 use std::ffi::CString;
+use std::fs;
 use std::fs::File;
 use std::io::Read;
+use std::mem;
+use std::os::unix::io::{AsRawFd, RawFd};
+use std::ptr;
 use std::string::ToString;
 use std::ops::Drop;
+use std::time::Duration;
 
 mod error;
 
+#[cfg(feature = "mio")]
+mod mio_support;
+
 #[cfg(test)]
 mod tests;
 
@@ -26,7 +34,7 @@ TODO:
 
 /// Wrapper type for queue names that performs basic validation of queue names before calling
 /// out to C code.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Name(CString);
 
 impl Name {
@@ -64,6 +72,28 @@ pub struct Message {
     pub priority: u32,
 }
 
+/// Live status of an open queue, as reported by `mq_getattr`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QueueStatus {
+    /// Number of messages currently pending in the queue.
+    pub pending_messages: i64,
+
+    /// The queue's current `mq_flags` (e.g. whether `O_NONBLOCK` is set).
+    pub flags: i64,
+}
+
+/// Entry returned by `Queue::list`, describing a queue without having to open it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueueInfo {
+    pub name: Name,
+
+    /// Total size in bytes of all messages currently queued (`QSIZE` in `/dev/mqueue`).
+    pub queue_size: i64,
+
+    /// PID registered for notification via `mq_notify`, or 0 if none is registered.
+    pub notify_pid: i32,
+}
+
 /// Represents an open queue descriptor to a POSIX message queue. This carries information
 /// about the queue's limitations (i.e. maximum message size and maximum message count).
 #[derive(Debug)]
@@ -84,99 +114,39 @@ impl Queue {
     /// Creates a new queue and fails if it already exists.
     /// By default the queue will be read/writable by the current user with no access for other
     /// users.
-    /// Linux users can change this setting themselves by modifying the queue file in /dev/mqueue.
+    /// Linux users can change this setting themselves by modifying the queue file in /dev/mqueue,
+    /// or by passing a different mode to `QueueBuilder`.
     pub fn create(name: Name, max_pending: i64, max_size: i64) -> Result<Queue, Error> {
-        if max_pending > read_i64_from_file(MSG_MAX)? {
-            return Err(Error::MaximumMessageCountExceeded());
-        }
-
-        if max_size > read_i64_from_file(MSGSIZE_MAX)? {
-            return Err(Error::MaximumMessageSizeExceeded());
-        }
-
-        let oflags = {
-            let mut flags = mqueue::MQ_OFlag::empty();
-            // Put queue in r/w mode
-            flags.toggle(mqueue::O_RDWR);
-            // Enable queue creation
-            flags.toggle(mqueue::O_CREAT);
-            // Fail if queue exists already
-            flags.toggle(mqueue::O_EXCL);
-            flags
-        };
-
-        let attr = mqueue::MqAttr::new(
-            0, max_pending, max_size, 0
-        );
-
-        let queue_descriptor = mqueue::mq_open(
-            &name.0,
-            oflags,
-            default_mode(),
-            Some(&attr),
-        )?;
-
-        Ok(Queue {
-            name,
-            queue_descriptor,
-            max_pending,
-            max_size: max_size as usize,
-        })
+        QueueBuilder::new(name)
+            .create(true)
+            .exclusive(true)
+            .max_pending(max_pending)
+            .max_size(max_size)
+            .open()
     }
 
     /// Opens an existing queue.
     pub fn open(name: Name) -> Result<Queue, Error> {
-        // No extra flags need to be constructed as the default is to open and fail if the
-        // queue does not exist yet - which is what we want here.
-        let oflags = mqueue::O_RDWR;
-        let queue_descriptor = mqueue::mq_open(
-            &name.0,
-            oflags,
-            default_mode(),
-            None,
-        )?;
-
-        let attr = mq_getattr(queue_descriptor)?;
-
-        Ok(Queue {
-            name,
-            queue_descriptor,
-            max_pending: attr.mq_maxmsg,
-            max_size: attr.mq_msgsize as usize,
-        })
+        QueueBuilder::new(name).open()
     }
 
     /// Opens an existing queue or creates a new queue with the OS default settings.
     pub fn open_or_create(name: Name) -> Result<Queue, Error> {
-        let oflags = {
-            let mut flags = mqueue::MQ_OFlag::empty();
-            // Put queue in r/w mode
-            flags.toggle(mqueue::O_RDWR);
-            // Enable queue creation
-            flags.toggle(mqueue::O_CREAT);
-            flags
-        };
-
-        let default_pending = read_i64_from_file(MSG_DEFAULT)?;
-        let default_size = read_i64_from_file(MSGSIZE_DEFAULT)?;
-        let attr = mqueue::MqAttr::new(
-            0, default_pending, default_size, 0
-        );
-
-        let queue_descriptor = mqueue::mq_open(
-            &name.0,
-            oflags,
-            default_mode(),
-            Some(&attr),
-        )?;
+        QueueBuilder::new(name).create(true).open()
+    }
 
-        let actual_attr = mq_getattr(queue_descriptor)?;
+    /// Adopts an already-open queue descriptor, e.g. one inherited from another process or
+    /// obtained from a raw `mq_open` call, filling in `max_pending`/`max_size` via `mq_getattr`.
+    /// `name` is not verified against the descriptor and is only used for `delete`.
+    pub fn from_raw_fd(name: Name, fd: RawFd) -> Result<Queue, Error> {
+        let queue_descriptor = fd as mqd_t;
+        let attr = mq_getattr(queue_descriptor)?;
 
         Ok(Queue {
             name,
             queue_descriptor,
-            max_pending: actual_attr.mq_maxmsg,
-            max_size: actual_attr.mq_msgsize as usize,
+            max_pending: attr.mq_maxmsg,
+            max_size: attr.mq_msgsize as usize,
         })
     }
 
@@ -191,7 +161,7 @@ impl Queue {
     /// Send a message to the message queue.
     /// If the queue is full this call will block until a message has been consumed.
     pub fn send(&self, msg: &Message) -> Result<(), Error> {
-        if msg.data.len() > self.max_size as usize {
+        if msg.data.len() > self.max_size {
             return Err(Error::MessageSizeExceeded());
         }
 
@@ -202,22 +172,158 @@ impl Queue {
         ).map_err(|e| e.into())
     }
 
-    /// Receive a message from the message queue.
-    /// If the queue is empty this call will block until a message arrives.
-    pub fn receive(&self) -> Result<Message, Error> {
-        let mut data: Vec<u8> = vec![0; self.max_size as usize];
+    /// Receive a message from the message queue into a caller-owned, reusable buffer, avoiding
+    /// the per-message allocation `receive` makes. `buf` is grown to `max_size` if it is not
+    /// already at least that long, then truncated to the received message's length - reusing
+    /// the same `buf` across calls only reallocates if it was never sized up to `max_size`.
+    /// Returns the message priority and the number of bytes written into `buf`.
+    pub fn receive_into(&self, buf: &mut Vec<u8>) -> Result<(u32, usize), Error> {
+        if buf.len() < self.max_size {
+            buf.resize(self.max_size, 0);
+        }
+
         let mut priority: u32 = 0;
 
         let msg_size = mqueue::mq_receive(
             self.queue_descriptor,
-            data.as_mut(),
+            buf.as_mut(),
             &mut priority,
         )?;
 
-        data.truncate(msg_size);
+        buf.truncate(msg_size);
+        Ok((priority, msg_size))
+    }
+
+    /// Receive a message from the message queue.
+    /// If the queue is empty this call will block until a message arrives.
+    pub fn receive(&self) -> Result<Message, Error> {
+        let mut data: Vec<u8> = Vec::new();
+        let (priority, _) = self.receive_into(&mut data)?;
         Ok(Message { data, priority })
     }
 
+    /// Send a message to the message queue, blocking for at most `timeout` if the queue is
+    /// full. Returns `Error::QueueCallTimedOut` if no space became available in time.
+    pub fn send_timeout(&self, msg: &Message, timeout: Duration) -> Result<(), Error> {
+        if msg.data.len() > self.max_size {
+            return Err(Error::MessageSizeExceeded());
+        }
+
+        let deadline = absolute_timespec(timeout)?;
+
+        let res = unsafe {
+            libc::mq_timedsend(
+                self.queue_descriptor,
+                msg.data.as_ptr() as *const libc::c_char,
+                msg.data.len(),
+                msg.priority,
+                &deadline,
+            )
+        };
+
+        nix::Errno::result(res).map(|_| ()).map_err(|e| e.into())
+    }
+
+    /// Receive a message from the message queue, blocking for at most `timeout` if the queue
+    /// is empty. Returns `Error::QueueCallTimedOut` if no message arrived in time.
+    pub fn receive_timeout(&self, timeout: Duration) -> Result<Message, Error> {
+        let mut data: Vec<u8> = vec![0; self.max_size];
+        let mut priority: u32 = 0;
+
+        let deadline = absolute_timespec(timeout)?;
+
+        let msg_size = unsafe {
+            libc::mq_timedreceive(
+                self.queue_descriptor,
+                data.as_mut_ptr() as *mut libc::c_char,
+                data.len(),
+                &mut priority,
+                &deadline,
+            )
+        };
+
+        let msg_size = nix::Errno::result(msg_size).map_err(Error::from)?;
+
+        data.truncate(msg_size as usize);
+        Ok(Message { data, priority })
+    }
+
+    /// Sends a message, returning `Error::WouldBlock` immediately instead of blocking if the
+    /// queue is full. Implemented as `send_timeout` with a zero timeout rather than toggling
+    /// `O_NONBLOCK`: `mq_flags` lives on the open file description, which is shared by every
+    /// `Queue` referring to the same descriptor (e.g. via `Arc<Queue>`), so flipping it here
+    /// would race with `send`/`receive` calls made concurrently through another reference.
+    pub fn try_send(&self, msg: &Message) -> Result<(), Error> {
+        match self.send_timeout(msg, Duration::from_secs(0)) {
+            Err(Error::QueueCallTimedOut()) => Err(Error::WouldBlock()),
+            result => result,
+        }
+    }
+
+    /// Receives a message, returning `Error::WouldBlock` immediately instead of blocking if the
+    /// queue is empty. Implemented as `receive_timeout` with a zero timeout for the same reason
+    /// `try_send` is implemented on top of `send_timeout` - see its documentation.
+    pub fn try_receive(&self) -> Result<Message, Error> {
+        match self.receive_timeout(Duration::from_secs(0)) {
+            Err(Error::QueueCallTimedOut()) => Err(Error::WouldBlock()),
+            result => result,
+        }
+    }
+
+    /// Switches the queue between blocking and non-blocking mode by updating its `mq_flags`
+    /// via `mq_setattr`. In non-blocking mode `send`/`receive` (and `try_send`/`try_receive`)
+    /// fail immediately with `Error::WouldBlock` instead of blocking.
+    pub fn set_nonblocking(&self, nonblocking: bool) -> Result<(), Error> {
+        let mut attr = mq_getattr(self.queue_descriptor)?;
+
+        if nonblocking {
+            attr.mq_flags |= libc::O_NONBLOCK as libc::c_long;
+        } else {
+            attr.mq_flags &= !(libc::O_NONBLOCK as libc::c_long);
+        }
+
+        mq_setattr(self.queue_descriptor, &attr)
+    }
+
+    /// Registers for a one-shot `SIGEV_SIGNAL` notification: `signo` is delivered to this
+    /// process the next time the queue transitions from empty to non-empty. The registration is
+    /// consumed by that delivery, so the signal handler must call `notify_signal` again to keep
+    /// receiving notifications. Only one process may be registered at a time; returns
+    /// `Error::NotifyAlreadyRegistered` if another process already holds the registration.
+    ///
+    /// There is no `notify_thread`/`SIGEV_THREAD` counterpart: the `libc` crate does not expose
+    /// the `sigevent` union members (`sigev_notify_function`/`sigev_notify_attributes`) needed to
+    /// set it up, only `sigev_value`, `sigev_signo`, `sigev_notify` and `sigev_notify_thread_id`.
+    pub fn notify_signal(&self, signo: libc::c_int) -> Result<(), Error> {
+        // All of `sigevent`'s fields are C ints/unions for which an all-zero bit pattern is
+        // valid, which matters here specifically because mq_notify reads the struct we pass in
+        // rather than fully overwriting it the way e.g. mq_getattr's out-param is.
+        let mut sigev = unsafe { mem::zeroed::<libc::sigevent>() };
+        sigev.sigev_notify = libc::SIGEV_SIGNAL;
+        sigev.sigev_signo = signo;
+
+        let res = unsafe { libc::mq_notify(self.queue_descriptor, &sigev) };
+        nix::Errno::result(res).map(|_| ()).map_err(|e| e.into())
+    }
+
+    /// Deregisters any notification previously requested with `notify_signal`.
+    pub fn cancel_notify(&self) -> Result<(), Error> {
+        let res = unsafe { libc::mq_notify(self.queue_descriptor, ptr::null()) };
+        nix::Errno::result(res).map(|_| ()).map_err(|e| e.into())
+    }
+
+    /// Reports the number of messages currently pending in the queue and its current
+    /// `mq_flags`, unlike `max_pending`/`max_size` which only report the limits cached at open
+    /// time.
+    pub fn current_messages(&self) -> Result<QueueStatus, Error> {
+        let attr = mq_getattr(self.queue_descriptor)?;
+
+        Ok(QueueStatus {
+            pending_messages: attr.mq_curmsgs,
+            flags: attr.mq_flags,
+        })
+    }
+
     pub fn max_pending(&self) -> i64 {
         self.max_pending
     }
@@ -225,6 +331,52 @@ impl Queue {
     pub fn max_size(&self) -> usize {
         self.max_size
     }
+
+    /// Lists every queue currently registered on the system by reading `/dev/mqueue`, without
+    /// having to open each one. Requires `mqueue` to be mounted there, which is the default on
+    /// Linux.
+    pub fn list() -> Result<Vec<QueueInfo>, Error> {
+        let mut infos = Vec::new();
+
+        for entry in fs::read_dir(MQUEUE_DIR)? {
+            let entry = entry?;
+
+            let name = Name::new(format!("/{}", entry.file_name().to_string_lossy()))?;
+
+            let mut status = String::new();
+            File::open(entry.path())?.read_to_string(&mut status)?;
+
+            let (queue_size, notify_pid) = parse_mqueue_status(&status)?;
+
+            infos.push(QueueInfo { name, queue_size, notify_pid });
+        }
+
+        Ok(infos)
+    }
+}
+
+/// Directory Linux exposes one status file per queue under, each containing a single line such
+/// as `QSIZE:66  NOTIFY:0  SIGNO:0  NOTIFY_PID:0`.
+const MQUEUE_DIR: &str = "/dev/mqueue";
+
+/// Parses a `/dev/mqueue/<name>` status line into `(QSIZE, NOTIFY_PID)`.
+fn parse_mqueue_status(status: &str) -> Result<(i64, i32), Error> {
+    let mut queue_size = 0;
+    let mut notify_pid = 0;
+
+    for field in status.split_whitespace() {
+        let mut parts = field.splitn(2, ':');
+        let key = parts.next().unwrap_or("");
+        let value = parts.next().unwrap_or("");
+
+        match key {
+            "QSIZE" => queue_size = value.parse()?,
+            "NOTIFY_PID" => notify_pid = value.parse()?,
+            _ => {}
+        }
+    }
+
+    Ok((queue_size, notify_pid))
 }
 
 impl Drop for Queue {
@@ -236,6 +388,170 @@ impl Drop for Queue {
     }
 }
 
+impl AsRawFd for Queue {
+    /// On Linux an `mqd_t` is backed by a regular file descriptor, so it can be waited on
+    /// alongside sockets and other fds in an event loop (in combination with non-blocking mode).
+    fn as_raw_fd(&self) -> RawFd {
+        self.queue_descriptor
+    }
+}
+
+/// The access mode a queue is opened with, mirroring the POSIX `O_RDONLY`/`O_WRONLY`/`O_RDWR`
+/// open flags.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AccessMode {
+    ReadOnly,
+    WriteOnly,
+    ReadWrite,
+}
+
+/// Builds a `Queue` from a composable set of options, replacing the proliferation of
+/// `create`/`open`/`open_or_create` combinations. `Queue::create`, `Queue::open` and
+/// `Queue::open_or_create` are thin wrappers around this builder for the common cases.
+#[derive(Debug)]
+pub struct QueueBuilder {
+    name: Name,
+    mode: stat::Mode,
+    access: AccessMode,
+    create: bool,
+    exclusive: bool,
+    nonblocking: bool,
+    max_pending: Option<i64>,
+    max_size: Option<i64>,
+}
+
+impl QueueBuilder {
+    /// Starts building a queue, defaulting to the same settings as `Queue::open`: read/write
+    /// access to an existing queue, mode 0600.
+    pub fn new(name: Name) -> QueueBuilder {
+        QueueBuilder {
+            name,
+            mode: default_mode(),
+            access: AccessMode::ReadWrite,
+            create: false,
+            exclusive: false,
+            nonblocking: false,
+            max_pending: None,
+            max_size: None,
+        }
+    }
+
+    /// Sets the permissions the queue is created with. Has no effect unless `create(true)` is
+    /// also set. Defaults to 0600; pass e.g. `0660` to share a queue with a group.
+    pub fn mode(mut self, mode: stat::Mode) -> QueueBuilder {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets whether the queue is opened read-only, write-only or read/write. Defaults to
+    /// read/write.
+    pub fn access(mut self, access: AccessMode) -> QueueBuilder {
+        self.access = access;
+        self
+    }
+
+    /// Sets whether the queue is created if it does not exist yet. Defaults to `false`.
+    pub fn create(mut self, create: bool) -> QueueBuilder {
+        self.create = create;
+        self
+    }
+
+    /// Sets whether queue creation fails if the queue already exists. Has no effect unless
+    /// `create(true)` is also set. Defaults to `false`.
+    pub fn exclusive(mut self, exclusive: bool) -> QueueBuilder {
+        self.exclusive = exclusive;
+        self
+    }
+
+    /// Sets whether the queue is opened in non-blocking mode. See `Queue::set_nonblocking`.
+    /// Defaults to `false`.
+    pub fn nonblocking(mut self, nonblocking: bool) -> QueueBuilder {
+        self.nonblocking = nonblocking;
+        self
+    }
+
+    /// Sets the maximum number of pending messages for a newly created queue. Has no effect
+    /// unless `create(true)` is also set; defaults to the system default (`msg_default`).
+    pub fn max_pending(mut self, max_pending: i64) -> QueueBuilder {
+        self.max_pending = Some(max_pending);
+        self
+    }
+
+    /// Sets the maximum message size for a newly created queue. Has no effect unless
+    /// `create(true)` is also set; defaults to the system default (`msgsize_default`).
+    pub fn max_size(mut self, max_size: i64) -> QueueBuilder {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    /// Opens (and, if requested, creates) the queue with the configured options.
+    pub fn open(self) -> Result<Queue, Error> {
+        let attr = if self.create {
+            let max_pending = match self.max_pending {
+                Some(max_pending) => max_pending,
+                None => read_i64_from_file(MSG_DEFAULT)?,
+            };
+
+            let max_size = match self.max_size {
+                Some(max_size) => max_size,
+                None => read_i64_from_file(MSGSIZE_DEFAULT)?,
+            };
+
+            if max_pending > read_i64_from_file(MSG_MAX)? {
+                return Err(Error::MaximumMessageCountExceeded());
+            }
+
+            if max_size > read_i64_from_file(MSGSIZE_MAX)? {
+                return Err(Error::MaximumMessageSizeExceeded());
+            }
+
+            Some(mqueue::MqAttr::new(0, max_pending, max_size, 0))
+        } else {
+            None
+        };
+
+        let oflags = {
+            let mut flags = mqueue::MQ_OFlag::empty();
+
+            flags.toggle(match self.access {
+                AccessMode::ReadOnly => mqueue::O_RDONLY,
+                AccessMode::WriteOnly => mqueue::O_WRONLY,
+                AccessMode::ReadWrite => mqueue::O_RDWR,
+            });
+
+            if self.create {
+                flags.toggle(mqueue::O_CREAT);
+            }
+
+            if self.exclusive {
+                flags.toggle(mqueue::O_EXCL);
+            }
+
+            if self.nonblocking {
+                flags.toggle(mqueue::O_NONBLOCK);
+            }
+
+            flags
+        };
+
+        let queue_descriptor = mqueue::mq_open(
+            &self.name.0,
+            oflags,
+            self.mode,
+            attr.as_ref(),
+        )?;
+
+        let actual_attr = mq_getattr(queue_descriptor)?;
+
+        Ok(Queue {
+            name: self.name,
+            queue_descriptor,
+            max_pending: actual_attr.mq_maxmsg,
+            max_size: actual_attr.mq_msgsize as usize,
+        })
+    }
+}
+
 // Creates the default queue mode (0600).
 fn default_mode() -> stat::Mode {
     let mut mode = stat::Mode::empty();
@@ -245,20 +561,20 @@ fn default_mode() -> stat::Mode {
 }
 
 /// This file defines the default number of maximum pending messages in a queue.
-const MSG_DEFAULT: &'static str = "/proc/sys/fs/mqueue/msg_default";
+const MSG_DEFAULT: &str = "/proc/sys/fs/mqueue/msg_default";
 
 /// This file defines the system maximum number of pending messages in a queue.
-const MSG_MAX: &'static str = "/proc/sys/fs/mqueue/msg_max";
+const MSG_MAX: &str = "/proc/sys/fs/mqueue/msg_max";
 
 /// This file defines the default maximum size of messages in a queue.
-const MSGSIZE_DEFAULT: &'static str = "/proc/sys/fs/mqueue/msgsize_default";
+const MSGSIZE_DEFAULT: &str = "/proc/sys/fs/mqueue/msgsize_default";
 
 /// This file defines the system maximum size for messages in a queue.
-const MSGSIZE_MAX: &'static str = "/proc/sys/fs/mqueue/msgsize_max";
+const MSGSIZE_MAX: &str = "/proc/sys/fs/mqueue/msgsize_max";
 
 /// This method is used in combination with the above constants to find system limits.
 fn read_i64_from_file(name: &str) -> Result<i64, Error> {
-    let mut file = File::open(name.to_string())?;
+    let mut file = File::open(name)?;
     let mut content = String::new();
     file.read_to_string(&mut content)?;
     Ok(content.trim().parse()?)
@@ -268,10 +584,35 @@ fn read_i64_from_file(name: &str) -> Result<i64, Error> {
 /// is very impractical.
 /// To work around it, this method calls the C-function directly.
 fn mq_getattr(mqd: mqd_t) -> Result<libc::mq_attr, Error> {
-    use std::mem;
-    let mut attr = unsafe { mem::uninitialized::<libc::mq_attr>() };
+    let mut attr = unsafe { mem::zeroed::<libc::mq_attr>() };
     let res = unsafe { libc::mq_getattr(mqd, &mut attr) };
     nix::Errno::result(res)
         .map(|_| attr)
         .map_err(|e| e.into())
 }
+
+/// The nix crate does not expose mq_setattr at all, so - just like mq_getattr above - this calls
+/// the C-function directly. Only `mq_flags` (i.e. O_NONBLOCK) may actually be changed this way;
+/// the kernel silently ignores changes to the other fields.
+fn mq_setattr(mqd: mqd_t, attr: &libc::mq_attr) -> Result<(), Error> {
+    let res = unsafe { libc::mq_setattr(mqd, attr, ptr::null_mut()) };
+    nix::Errno::result(res).map(|_| ()).map_err(|e| e.into())
+}
+
+/// mq_timedsend/mq_timedreceive take an absolute CLOCK_REALTIME deadline rather than a relative
+/// duration, so this adds `timeout` to the current time.
+fn absolute_timespec(timeout: Duration) -> Result<libc::timespec, Error> {
+    let mut now = unsafe { mem::zeroed::<libc::timespec>() };
+    let res = unsafe { libc::clock_gettime(libc::CLOCK_REALTIME, &mut now) };
+    nix::Errno::result(res).map_err(Error::from)?;
+
+    let mut secs = now.tv_sec + timeout.as_secs() as libc::time_t;
+    let mut nsecs = now.tv_nsec + timeout.subsec_nanos() as libc::c_long;
+
+    if nsecs >= 1_000_000_000 {
+        secs += 1;
+        nsecs -= 1_000_000_000;
+    }
+
+    Ok(libc::timespec { tv_sec: secs, tv_nsec: nsecs })
+}
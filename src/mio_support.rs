@@ -0,0 +1,34 @@
+extern crate mio;
+
+use Queue;
+use std::io;
+use std::os::unix::io::AsRawFd;
+
+/// Registers a `Queue` for readiness notifications in a `mio` event loop, keyed off its raw
+/// queue descriptor. Requires the queue to be opened in non-blocking mode (see
+/// `Queue::set_nonblocking`) to avoid blocking the reactor thread on `send`/`receive`.
+impl mio::Evented for Queue {
+    fn register(
+        &self,
+        poll: &mio::Poll,
+        token: mio::Token,
+        interest: mio::Ready,
+        opts: mio::PollOpt,
+    ) -> io::Result<()> {
+        mio::unix::EventedFd(&self.as_raw_fd()).register(poll, token, interest, opts)
+    }
+
+    fn reregister(
+        &self,
+        poll: &mio::Poll,
+        token: mio::Token,
+        interest: mio::Ready,
+        opts: mio::PollOpt,
+    ) -> io::Result<()> {
+        mio::unix::EventedFd(&self.as_raw_fd()).reregister(poll, token, interest, opts)
+    }
+
+    fn deregister(&self, poll: &mio::Poll) -> io::Result<()> {
+        mio::unix::EventedFd(&self.as_raw_fd()).deregister(poll)
+    }
+}
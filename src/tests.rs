@@ -18,5 +18,170 @@ fn test_open_delete() {
 
     assert_eq!(message, result);
 
-    queue.delete();
+    queue.delete().expect("message deletion failed");
+}
+
+#[test]
+fn test_queue_builder() {
+    // A queue built with explicit mode/access/size options should honour them rather than
+    // falling back to the `Queue::open_or_create` defaults.
+    let name = Name::new("/test-queue-builder").unwrap();
+    let queue = QueueBuilder::new(name)
+        .create(true)
+        .exclusive(true)
+        .access(AccessMode::ReadWrite)
+        .mode(stat::Mode::from_bits_truncate(0o600))
+        .max_pending(5)
+        .max_size(128)
+        .open()
+        .expect("Building queue failed");
+
+    assert_eq!(queue.max_pending(), 5);
+    assert_eq!(queue.max_size(), 128);
+
+    let message = Message {
+        data: "builder-message".as_bytes().to_vec(),
+        priority: 0,
+    };
+
+    queue.send(&message).expect("message sending failed");
+    let result = queue.receive().expect("message receiving failed");
+    assert_eq!(message, result);
+
+    queue.delete().expect("message deletion failed");
+}
+
+#[test]
+fn test_receive_into_reuses_buffer() {
+    // The same `Vec` should be reused (and just resized/truncated) across repeated receives
+    // instead of being reallocated every time.
+    let name = Name::new("/test-queue-receive-into").unwrap();
+    let queue = Queue::open_or_create(name)
+        .expect("Opening queue failed");
+
+    queue.send(&Message { data: vec![1, 2, 3], priority: 0 })
+        .expect("message sending failed");
+
+    let mut buf = Vec::new();
+    let (priority, size) = queue.receive_into(&mut buf).expect("message receiving failed");
+    assert_eq!(priority, 0);
+    assert_eq!(size, 3);
+    assert_eq!(&buf[..], &[1, 2, 3]);
+
+    let reused_ptr = buf.as_ptr();
+
+    queue.send(&Message { data: vec![4, 5], priority: 0 })
+        .expect("message sending failed");
+
+    let (_, size) = queue.receive_into(&mut buf).expect("message receiving failed");
+    assert_eq!(size, 2);
+    assert_eq!(&buf[..], &[4, 5]);
+    assert_eq!(buf.as_ptr(), reused_ptr);
+
+    queue.delete().expect("message deletion failed");
+}
+
+#[test]
+fn test_send_timeout_times_out_on_full_queue() {
+    let name = Name::new("/test-queue-send-timeout").unwrap();
+    let queue = QueueBuilder::new(name)
+        .create(true)
+        .exclusive(true)
+        .max_pending(1)
+        .open()
+        .expect("Building queue failed");
+
+    let message = Message { data: vec![0], priority: 0 };
+
+    queue.send(&message).expect("message sending failed");
+
+    let result = queue.send_timeout(&message, Duration::from_millis(50));
+    assert!(matches!(result, Err(Error::QueueCallTimedOut())));
+
+    queue.delete().expect("message deletion failed");
+}
+
+#[test]
+fn test_receive_timeout_times_out_on_empty_queue() {
+    let name = Name::new("/test-queue-receive-timeout").unwrap();
+    let queue = Queue::open_or_create(name)
+        .expect("Opening queue failed");
+
+    let result = queue.receive_timeout(Duration::from_millis(50));
+    assert!(matches!(result, Err(Error::QueueCallTimedOut())));
+
+    queue.delete().expect("message deletion failed");
+}
+
+#[test]
+fn test_parse_mqueue_status() {
+    let status = parse_mqueue_status("QSIZE:66  NOTIFY:0  SIGNO:0  NOTIFY_PID:42\n").unwrap();
+    assert_eq!(status, (66, 42));
+}
+
+#[test]
+fn test_parse_mqueue_status_missing_fields_default_to_zero() {
+    let status = parse_mqueue_status("NOTIFY:0  SIGNO:0\n").unwrap();
+    assert_eq!(status, (0, 0));
+}
+
+#[test]
+fn test_parse_mqueue_status_malformed_number_is_an_error() {
+    let result = parse_mqueue_status("QSIZE:not-a-number  NOTIFY_PID:42\n");
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_notify_signal_rejects_second_registration() {
+    let name = Name::new("/test-queue-notify").unwrap();
+    let queue = Queue::open_or_create(name).expect("Opening queue failed");
+
+    queue.notify_signal(libc::SIGUSR1).expect("first notify registration failed");
+
+    let result = queue.notify_signal(libc::SIGUSR1);
+    assert!(matches!(result, Err(Error::NotifyAlreadyRegistered())));
+
+    queue.cancel_notify().expect("cancel_notify failed");
+
+    // Once cancelled, another registration should be accepted again.
+    queue.notify_signal(libc::SIGUSR1).expect("re-registration after cancel failed");
+    queue.cancel_notify().expect("cancel_notify failed");
+
+    queue.delete().expect("message deletion failed");
+}
+
+#[test]
+fn test_from_raw_fd_adopts_open_queue() {
+    let name = Name::new("/test-queue-from-raw-fd").unwrap();
+    let queue = Queue::create(name.clone(), 5, 128).expect("creating queue failed");
+    let fd = queue.as_raw_fd();
+
+    let adopted = Queue::from_raw_fd(name, fd).expect("adopting raw fd failed");
+    assert_eq!(adopted.max_pending(), 5);
+    assert_eq!(adopted.max_size(), 128);
+
+    // Both `Queue`s now own the same descriptor: drop the original without closing it, so the
+    // adopted one is left valid to delete.
+    mem::forget(queue);
+
+    adopted.delete().expect("message deletion failed");
+}
+
+#[cfg(feature = "mio")]
+#[test]
+fn test_mio_evented_registration() {
+    let name = Name::new("/test-queue-mio").unwrap();
+    let queue = Queue::open_or_create(name).expect("Opening queue failed");
+    queue.set_nonblocking(true).expect("failed to set nonblocking");
+
+    let poll = mio::Poll::new().expect("failed to create mio::Poll");
+    poll.register(&queue, mio::Token(0), mio::Ready::writable(), mio::PollOpt::edge())
+        .expect("failed to register queue with mio");
+
+    let mut events = mio::Events::with_capacity(16);
+    poll.poll(&mut events, Some(Duration::from_millis(200))).expect("poll failed");
+
+    assert!(events.iter().any(|event| event.token() == mio::Token(0)));
+
+    queue.delete().expect("message deletion failed");
 }